@@ -0,0 +1,91 @@
+use common_utils::types::TimeRange;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(
+    Debug, Clone, Copy, Eq, PartialEq, Deserialize, Serialize, ToSchema, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MandateStatus {
+    Active,
+    Inactive,
+    Pending,
+    Revoked,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct MandateResponse {
+    pub mandate_id: String,
+    pub status: MandateStatus,
+    pub connector: String,
+    pub created: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct MandateListConstraints {
+    pub limit: Option<i64>,
+    pub mandate_status: Option<MandateStatus>,
+    pub connector: Option<String>,
+    #[serde(flatten)]
+    pub created_time: Option<TimeRange>,
+    /// Legacy paging, kept for backward compatibility. Can skip/duplicate rows under concurrent
+    /// inserts; prefer `starting_after`/`ending_before`.
+    pub offset: Option<i64>,
+    /// Cursor from a previous page's `next_cursor`.
+    pub starting_after: Option<String>,
+    /// Cursor from a previous page's `prev_cursor`.
+    pub ending_before: Option<String>,
+    /// Filter expression, e.g. `connector = "stripe" AND mandate_status != "revoked"`. When
+    /// present, `mandate_status`, `connector` and `created_time` are ignored; paging fields still apply.
+    pub filter: Option<String>,
+}
+
+/// One `(status, connector) -> count` bucket of the mandate analytics response.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MandateAnalyticsBucket {
+    pub mandate_status: MandateStatus,
+    pub connector: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MandateAnalyticsResponse {
+    pub buckets: Vec<MandateAnalyticsBucket>,
+}
+
+/// Envelope wrapping a page of [`MandateResponse`]s with keyset pagination cursors.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MandateListResponse {
+    pub data: Vec<MandateResponse>,
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+/// The same selection shape as [`MandateListConstraints`], plus an explicit id list, used by
+/// bulk operations that need to act on more than one mandate at a time.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct MandateRevokeConstraints {
+    #[serde(default)]
+    pub mandate_ids: Vec<String>,
+    pub mandate_status: Option<MandateStatus>,
+    pub connector: Option<String>,
+    #[serde(flatten)]
+    pub created_time: Option<TimeRange>,
+}
+
+/// Outcome of revoking a single mandate as part of a bulk request, so that one failure in the
+/// batch doesn't hide the rest of the results.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MandateRevokeResult {
+    pub mandate_id: String,
+    pub status: Option<MandateStatus>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MandateRevokeBulkResponse {
+    pub results: Vec<MandateRevokeResult>,
+}
@@ -0,0 +1,93 @@
+use base64::Engine;
+use common_utils::{consts::BASE64_ENGINE, date_time};
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::core::errors::{self, CustomResult};
+
+/// The opaque `starting_after`/`ending_before` cursor: a base64-encoded `created_time,mandate_id`
+/// keyset pair.
+#[derive(Debug, Clone)]
+pub struct ListCursor {
+    pub created_time: PrimitiveDateTime,
+    pub mandate_id: String,
+}
+
+impl ListCursor {
+    pub fn encode(&self) -> String {
+        let raw = format!(
+            "{},{}",
+            self.created_time.unix_timestamp_nanos(),
+            self.mandate_id
+        );
+        BASE64_ENGINE.encode(raw)
+    }
+
+    pub fn decode(cursor: &str) -> CustomResult<Self, errors::ApiErrorResponse> {
+        let decoded = BASE64_ENGINE
+            .decode(cursor)
+            .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: "invalid pagination cursor".to_string(),
+            })?;
+        let decoded = String::from_utf8(decoded)
+            .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: "invalid pagination cursor".to_string(),
+            })?;
+
+        let (timestamp, mandate_id) =
+            decoded
+                .split_once(',')
+                .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "invalid pagination cursor".to_string(),
+                })?;
+
+        let timestamp: i128 =
+            timestamp
+                .parse()
+                .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "invalid pagination cursor".to_string(),
+                })?;
+
+        Ok(Self {
+            created_time: date_time::from_unix_timestamp_nanos(timestamp),
+            mandate_id: mandate_id.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn round_trips_through_encode_and_decode() {
+        let cursor = ListCursor {
+            created_time: datetime!(2024-01-15 10:30:00),
+            mandate_id: "mandate_abc123".to_string(),
+        };
+
+        let decoded = ListCursor::decode(&cursor.encode()).unwrap();
+
+        assert_eq!(decoded.created_time, cursor.created_time);
+        assert_eq!(decoded.mandate_id, cursor.mandate_id);
+    }
+
+    #[test]
+    fn rejects_non_base64_input() {
+        assert!(ListCursor::decode("not valid base64!!!").is_err());
+    }
+
+    #[test]
+    fn rejects_base64_without_a_comma_separator() {
+        let opaque = BASE64_ENGINE.encode("no-separator-here");
+        assert!(ListCursor::decode(&opaque).is_err());
+    }
+
+    #[test]
+    fn rejects_a_non_numeric_timestamp() {
+        let opaque = BASE64_ENGINE.encode("not-a-number,mandate_abc123");
+        assert!(ListCursor::decode(&opaque).is_err());
+    }
+}
@@ -0,0 +1,415 @@
+use crate::core::errors::{self, CustomResult};
+
+/// Mandate columns a filter expression is allowed to reference; anything else is rejected by
+/// [`parse`] before it can reach [`lower_to_sql`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Connector,
+    MandateStatus,
+    CreatedTime,
+    MandateId,
+}
+
+impl Column {
+    fn parse(ident: &str) -> Option<Self> {
+        match ident {
+            "connector" => Some(Self::Connector),
+            "mandate_status" => Some(Self::MandateStatus),
+            "created_time" => Some(Self::CreatedTime),
+            "mandate_id" => Some(Self::MandateId),
+            _ => None,
+        }
+    }
+
+    pub fn as_sql_identifier(self) -> &'static str {
+        match self {
+            Self::Connector => "connector",
+            Self::MandateStatus => "mandate_status",
+            Self::CreatedTime => "created_time",
+            Self::MandateId => "mandate_id",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    In,
+}
+
+impl CompareOp {
+    pub fn as_sql(self) -> &'static str {
+        match self {
+            Self::Eq => "=",
+            Self::Ne => "!=",
+            Self::Gt => ">",
+            Self::Gte => ">=",
+            Self::Lt => "<",
+            Self::Lte => "<=",
+            Self::In => "IN",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Value {
+    String(String),
+    List(Vec<String>),
+}
+
+/// A parsed filter expression tree, produced only by [`parse`].
+#[derive(Debug, Clone)]
+pub enum Expr {
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Compare {
+        column: Column,
+        op: CompareOp,
+        value: Value,
+    },
+}
+
+/// A compiled filter: `predicate` is a SQL fragment with `$1`, `$2`, ... placeholders, and
+/// `params` holds the bound values in order.
+#[derive(Debug, Clone)]
+pub struct CompiledFilter {
+    pub predicate: String,
+    pub params: Vec<String>,
+}
+
+/// Parses a filter expression like `connector = "stripe" AND mandate_status != "revoked"` into
+/// an [`Expr`] tree, with precedence `OR` < `AND` < `NOT` < comparison.
+pub fn parse(input: &str) -> CustomResult<Expr, errors::ApiErrorResponse> {
+    let tokens = tokenize(input)?;
+    let mut parser = Parser { tokens, pos: 0 };
+    let expr = parser.parse_or()?;
+    if parser.pos != parser.tokens.len() {
+        return Err(invalid("unexpected trailing tokens in filter expression"));
+    }
+    Ok(expr)
+}
+
+fn invalid(message: &str) -> error_stack::Report<errors::ApiErrorResponse> {
+    errors::ApiErrorResponse::InvalidRequestData {
+        message: format!("invalid filter expression: {message}"),
+    }
+    .into()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Ident(String),
+    StringLit(String),
+    Op(String),
+    LParen,
+    RParen,
+    And,
+    Or,
+    Not,
+    LBracket,
+    RBracket,
+    Comma,
+}
+
+fn tokenize(input: &str) -> CustomResult<Vec<Token>, errors::ApiErrorResponse> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::LParen);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::RParen);
+            }
+            '[' => {
+                chars.next();
+                tokens.push(Token::LBracket);
+            }
+            ']' => {
+                chars.next();
+                tokens.push(Token::RBracket);
+            }
+            ',' => {
+                chars.next();
+                tokens.push(Token::Comma);
+            }
+            '"' => {
+                chars.next();
+                let mut literal = String::new();
+                for c in chars.by_ref() {
+                    if c == '"' {
+                        break;
+                    }
+                    literal.push(c);
+                }
+                tokens.push(Token::StringLit(literal));
+            }
+            '=' | '!' | '>' | '<' => {
+                let mut op = String::new();
+                op.push(c);
+                chars.next();
+                if matches!(chars.peek(), Some('=')) {
+                    op.push('=');
+                    chars.next();
+                }
+                if op == "!" {
+                    return Err(invalid("'!' must be followed by '='"));
+                }
+                tokens.push(Token::Op(op));
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut ident = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_alphanumeric() || c == '_' {
+                        ident.push(c);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                match ident.as_str() {
+                    "AND" => tokens.push(Token::And),
+                    "OR" => tokens.push(Token::Or),
+                    "NOT" => tokens.push(Token::Not),
+                    "IN" => tokens.push(Token::Op("IN".to_string())),
+                    _ => tokens.push(Token::Ident(ident)),
+                }
+            }
+            _ => return Err(invalid("unexpected character")),
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn advance(&mut self) -> Option<&Token> {
+        let token = self.tokens.get(self.pos);
+        self.pos += 1;
+        token
+    }
+
+    fn parse_or(&mut self) -> CustomResult<Expr, errors::ApiErrorResponse> {
+        let mut lhs = self.parse_and()?;
+        while matches!(self.peek(), Some(Token::Or)) {
+            self.advance();
+            let rhs = self.parse_and()?;
+            lhs = Expr::Or(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> CustomResult<Expr, errors::ApiErrorResponse> {
+        let mut lhs = self.parse_not()?;
+        while matches!(self.peek(), Some(Token::And)) {
+            self.advance();
+            let rhs = self.parse_not()?;
+            lhs = Expr::And(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_not(&mut self) -> CustomResult<Expr, errors::ApiErrorResponse> {
+        if matches!(self.peek(), Some(Token::Not)) {
+            self.advance();
+            return Ok(Expr::Not(Box::new(self.parse_not()?)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> CustomResult<Expr, errors::ApiErrorResponse> {
+        match self.peek() {
+            Some(Token::LParen) => {
+                self.advance();
+                let expr = self.parse_or()?;
+                match self.advance() {
+                    Some(Token::RParen) => Ok(expr),
+                    _ => Err(invalid("expected ')'")),
+                }
+            }
+            _ => self.parse_comparison(),
+        }
+    }
+
+    fn parse_comparison(&mut self) -> CustomResult<Expr, errors::ApiErrorResponse> {
+        let column = match self.advance() {
+            Some(Token::Ident(ident)) => {
+                Column::parse(ident).ok_or_else(|| invalid(&format!("unknown column '{ident}'")))?
+            }
+            _ => return Err(invalid("expected a column name")),
+        };
+
+        let op = match self.advance() {
+            Some(Token::Op(op)) => match op.as_str() {
+                "=" => CompareOp::Eq,
+                "!=" => CompareOp::Ne,
+                ">" => CompareOp::Gt,
+                ">=" => CompareOp::Gte,
+                "<" => CompareOp::Lt,
+                "<=" => CompareOp::Lte,
+                "IN" => CompareOp::In,
+                other => return Err(invalid(&format!("unsupported operator '{other}'"))),
+            },
+            _ => return Err(invalid("expected a comparison operator")),
+        };
+
+        let value = if op == CompareOp::In {
+            match self.advance() {
+                Some(Token::LBracket) => {}
+                _ => return Err(invalid("expected '[' after IN")),
+            }
+            let mut values = Vec::new();
+            loop {
+                match self.advance() {
+                    Some(Token::StringLit(value)) => values.push(value.clone()),
+                    _ => return Err(invalid("expected a string literal in IN list")),
+                }
+                match self.advance() {
+                    Some(Token::Comma) => continue,
+                    Some(Token::RBracket) => break,
+                    _ => return Err(invalid("expected ',' or ']' in IN list")),
+                }
+            }
+            Value::List(values)
+        } else {
+            match self.advance() {
+                Some(Token::StringLit(value)) => Value::String(value.clone()),
+                _ => return Err(invalid("expected a string literal")),
+            }
+        };
+
+        Ok(Expr::Compare { column, op, value })
+    }
+}
+
+/// Lowers a parsed [`Expr`] to a parameterized SQL fragment plus its bound parameters.
+pub fn lower_to_sql(expr: &Expr) -> CompiledFilter {
+    let mut params = Vec::new();
+    let predicate = lower_expr(expr, &mut params);
+    CompiledFilter { predicate, params }
+}
+
+fn lower_expr(expr: &Expr, params: &mut Vec<String>) -> String {
+    match expr {
+        Expr::And(lhs, rhs) => format!(
+            "({} AND {})",
+            lower_expr(lhs, params),
+            lower_expr(rhs, params)
+        ),
+        Expr::Or(lhs, rhs) => format!(
+            "({} OR {})",
+            lower_expr(lhs, params),
+            lower_expr(rhs, params)
+        ),
+        Expr::Not(inner) => format!("NOT ({})", lower_expr(inner, params)),
+        Expr::Compare { column, op, value } => match value {
+            Value::String(value) => {
+                params.push(value.clone());
+                format!(
+                    "{} {} ${}",
+                    column.as_sql_identifier(),
+                    op.as_sql(),
+                    params.len()
+                )
+            }
+            Value::List(values) => {
+                let placeholders: Vec<String> = values
+                    .iter()
+                    .map(|value| {
+                        params.push(value.clone());
+                        format!("${}", params.len())
+                    })
+                    .collect();
+                format!(
+                    "{} IN ({})",
+                    column.as_sql_identifier(),
+                    placeholders.join(", ")
+                )
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        // `a OR b AND c` should parse as `a OR (b AND c)`.
+        let expr = parse(r#"connector = "stripe" OR connector = "adyen" AND mandate_status = "active""#).unwrap();
+        assert!(matches!(expr, Expr::Or(_, _)));
+    }
+
+    #[test]
+    fn not_binds_tighter_than_and() {
+        let expr = parse(r#"NOT mandate_status = "revoked" AND connector = "stripe""#).unwrap();
+        match expr {
+            Expr::And(lhs, _) => assert!(matches!(*lhs, Expr::Not(_))),
+            other => panic!("expected And(Not(_), _), got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parentheses_override_precedence() {
+        let expr = parse(r#"connector = "stripe" AND (mandate_status = "active" OR mandate_status = "pending")"#)
+            .unwrap();
+        match expr {
+            Expr::And(_, rhs) => assert!(matches!(*rhs, Expr::Or(_, _))),
+            other => panic!("expected And(_, Or(_, _)), got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn in_list_lowers_to_one_placeholder_per_value() {
+        let expr = parse(r#"connector IN ["stripe", "adyen", "checkout"]"#).unwrap();
+        let compiled = lower_to_sql(&expr);
+        assert_eq!(compiled.predicate, "connector IN ($1, $2, $3)");
+        assert_eq!(compiled.params, vec!["stripe", "adyen", "checkout"]);
+    }
+
+    #[test]
+    fn placeholders_are_numbered_across_the_whole_tree() {
+        let expr = parse(r#"connector = "stripe" AND mandate_status != "revoked""#).unwrap();
+        let compiled = lower_to_sql(&expr);
+        assert_eq!(compiled.predicate, "(connector = $1 AND mandate_status != $2)");
+        assert_eq!(compiled.params, vec!["stripe", "revoked"]);
+    }
+
+    #[test]
+    fn rejects_columns_outside_the_whitelist() {
+        let error = parse(r#"merchant_secret = "whatever""#).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("unknown column 'merchant_secret'"));
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert!(parse(r#"connector = "stripe" AND"#).is_err());
+        assert!(parse(r#"connector stripe""#).is_err());
+        assert!(parse(r#"connector = "stripe""#).is_ok());
+    }
+}
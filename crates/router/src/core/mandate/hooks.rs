@@ -0,0 +1,180 @@
+use std::time::Duration;
+
+use common_utils::{ext_traits::Encode, id_type};
+use error_stack::ResultExt;
+use masking::{ExposeInterface, Secret};
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::errors::{self, CustomResult, RouterResponse},
+    db::mandate_hooks::MandateHooksInterface,
+    routes::app::AppState,
+    services::{self, ApplicationResponse},
+    types::{domain, storage},
+};
+
+/// The mandate state transitions a merchant can subscribe a hook to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MandateHookEvent {
+    Created,
+    Activated,
+    Revoked,
+    StatusChanged,
+}
+
+/// Where a hook's payload is delivered once a mandate event fires.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub enum HookAction {
+    /// `POST` the signed payload to a merchant-owned URL.
+    Webhook { url: String, signing_key: Secret<String> },
+    /// Hand off to the internal outgoing-webhooks job queue instead of calling out synchronously.
+    InternalJob,
+}
+
+/// A hook registered against one merchant account for one [`MandateHookEvent`].
+#[derive(Debug, Clone)]
+pub struct MandateHook {
+    pub merchant_id: id_type::MerchantId,
+    pub event: MandateHookEvent,
+    pub action: HookAction,
+}
+
+/// Request body for registering (or replacing) a merchant's mandate hook.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct MandateHookRegistration {
+    pub event: MandateHookEvent,
+    pub action: HookAction,
+}
+
+/// Registers `req` as the hook fired for `req.event` on `merchant_account`, replacing whatever
+/// was registered for that event before.
+pub async fn register(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: MandateHookRegistration,
+) -> RouterResponse<MandateHookRegistration> {
+    let db = state.store.as_ref();
+
+    let hook = db
+        .upsert_mandate_hook(MandateHook {
+            merchant_id: merchant_account.get_id().clone(),
+            event: req.event,
+            action: req.action,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to register mandate hook")?;
+
+    Ok(ApplicationResponse::Json(MandateHookRegistration {
+        event: hook.event,
+        action: hook.action,
+    }))
+}
+
+/// Body delivered to a hook's action, shared across the webhook and internal-job paths.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct MandateHookPayload {
+    pub mandate_id: String,
+    pub previous_status: Option<String>,
+    pub new_status: String,
+    pub connector: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub occurred_at: PrimitiveDateTime,
+}
+
+const MAX_DELIVERY_ATTEMPTS: u8 = 3;
+const RETRY_BACKOFF_BASE: Duration = Duration::from_millis(200);
+
+/// Fires every hook registered for `event` on `merchant_id` with `payload`. Delivery failures
+/// are retried and swallowed here, but a failed hook lookup still returns `Err` — go through
+/// `core::mandate::notify` rather than calling this directly.
+pub async fn dispatch(
+    state: &AppState,
+    merchant_id: &id_type::MerchantId,
+    event: MandateHookEvent,
+    payload: MandateHookPayload,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let hooks = state
+        .store
+        .find_mandate_hooks(merchant_id, event)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to load registered mandate hooks")?;
+
+    for hook in hooks {
+        if let Err(error) = deliver_with_retries(state, &hook, &payload).await {
+            router_env::logger::error!(
+                ?error,
+                mandate_id = %payload.mandate_id,
+                event = %event,
+                "mandate hook delivery exhausted retries"
+            );
+        }
+    }
+
+    Ok(())
+}
+
+async fn deliver_with_retries(
+    state: &AppState,
+    hook: &MandateHook,
+    payload: &MandateHookPayload,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let body = payload
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to serialize mandate hook payload")?;
+
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        let result = match &hook.action {
+            HookAction::Webhook { url, signing_key } => {
+                deliver_webhook(state, url, signing_key, &body).await
+            }
+            HookAction::InternalJob => {
+                storage::enqueue_mandate_hook_job(state, hook.merchant_id.clone(), body.clone())
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+            }
+        };
+
+        match result {
+            Ok(()) => return Ok(()),
+            Err(error) if attempt >= MAX_DELIVERY_ATTEMPTS => return Err(error),
+            Err(_) => {
+                tokio::time::sleep(RETRY_BACKOFF_BASE * u32::from(attempt)).await;
+            }
+        }
+    }
+}
+
+async fn deliver_webhook(
+    state: &AppState,
+    url: &str,
+    signing_key: &Secret<String>,
+    body: &str,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let signature = common_utils::crypto::HmacSha256
+        .sign_message(signing_key.clone().expose().as_bytes(), body.as_bytes())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to sign mandate hook payload")?;
+
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(url)
+        .header("X-Mandate-Signature", &hex::encode(signature))
+        .body(Some(services::RequestContent::RawBytes(
+            body.as_bytes().to_vec(),
+        )))
+        .build();
+
+    services::call_connector_api(state, request, "mandate hook delivery")
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("mandate hook webhook delivery failed")?;
+
+    Ok(())
+}
@@ -0,0 +1,356 @@
+pub mod cursor;
+pub mod filter;
+pub mod hooks;
+
+use api_models::mandates as mandate_types;
+use common_utils::id_type;
+use error_stack::ResultExt;
+
+use self::cursor::ListCursor;
+
+use crate::{
+    core::errors::{self, RouterResponse},
+    db::{mandate_filter::MandateFilterInterface, mandate_hooks::MandateHooksInterface},
+    routes::app::AppState,
+    services::ApplicationResponse,
+    types::domain,
+};
+
+/// Fires `event` and logs a failure instead of propagating it: a merchant's broken hook must
+/// never fail the mandate operation that triggered it.
+async fn notify(
+    state: &AppState,
+    merchant_id: &id_type::MerchantId,
+    event: hooks::MandateHookEvent,
+    mandate_id: &str,
+    previous_status: Option<mandate_types::MandateStatus>,
+    new_status: mandate_types::MandateStatus,
+    connector: &str,
+) {
+    let payload = hooks::MandateHookPayload {
+        mandate_id: mandate_id.to_string(),
+        previous_status: previous_status.map(|status| status.to_string()),
+        new_status: new_status.to_string(),
+        connector: connector.to_string(),
+        occurred_at: common_utils::date_time::now(),
+    };
+
+    if let Err(error) = hooks::dispatch(state, merchant_id, event, payload).await {
+        router_env::logger::error!(
+            ?error,
+            mandate_id,
+            ?event,
+            "failed to dispatch mandate hook"
+        );
+    }
+}
+
+/// Fires the `Created` lifecycle hook. Mandate creation happens in the payments core, which
+/// this module doesn't own; call this once that core persists a newly created mandate.
+pub async fn notify_mandate_created(
+    state: &AppState,
+    merchant_id: &id_type::MerchantId,
+    mandate_id: &str,
+    status: mandate_types::MandateStatus,
+    connector: &str,
+) {
+    notify(
+        state,
+        merchant_id,
+        hooks::MandateHookEvent::Created,
+        mandate_id,
+        None,
+        status,
+        connector,
+    )
+    .await;
+}
+
+/// Fetches a single mandate, and fires `Activated`/`StatusChanged` for whatever transition
+/// happened since it was last fetched (`Created` fires separately, from
+/// [`notify_mandate_created`], since creation isn't observable from a read).
+pub async fn get_mandate(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    _key_store: domain::MerchantKeyStore,
+    req: mandate_types::MandateId,
+) -> RouterResponse<mandate_types::MandateResponse> {
+    let db = state.store.as_ref();
+
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(merchant_account.get_id(), &req.mandate_id)
+        .await
+        .change_context(errors::ApiErrorResponse::MandateNotFound)?;
+
+    let previous_status = db
+        .update_mandate_last_notified_status(
+            merchant_account.get_id(),
+            &req.mandate_id,
+            mandate.mandate_status,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to update last-notified mandate status")?;
+
+    let event = match previous_status {
+        None => None,
+        Some(previous) if previous == mandate.mandate_status => None,
+        Some(_) if mandate.mandate_status == mandate_types::MandateStatus::Active => {
+            Some(hooks::MandateHookEvent::Activated)
+        }
+        Some(_) => Some(hooks::MandateHookEvent::StatusChanged),
+    };
+
+    if let Some(event) = event {
+        notify(
+            &state,
+            merchant_account.get_id(),
+            event,
+            &mandate.mandate_id,
+            previous_status,
+            mandate.mandate_status,
+            &mandate.connector,
+        )
+        .await;
+    }
+
+    Ok(ApplicationResponse::Json(mandate_types::MandateResponse {
+        mandate_id: mandate.mandate_id,
+        status: mandate.mandate_status,
+        connector: mandate.connector,
+        created: mandate.created,
+    }))
+}
+
+/// Revokes a single mandate and fires the `Revoked` lifecycle hook once the revocation commits.
+pub async fn revoke_mandate(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: mandate_types::MandateId,
+) -> RouterResponse<mandate_types::MandateResponse> {
+    let db = state.store.as_ref();
+
+    let previous_status = db
+        .find_mandate_by_merchant_id_mandate_id(merchant_account.get_id(), &req.mandate_id)
+        .await
+        .change_context(errors::ApiErrorResponse::MandateNotFound)?
+        .mandate_status;
+
+    let mandate = db
+        .revoke_mandate(merchant_account.get_id(), &key_store, &req.mandate_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to revoke mandate")?;
+
+    notify(
+        &state,
+        merchant_account.get_id(),
+        hooks::MandateHookEvent::Revoked,
+        &mandate.mandate_id,
+        Some(previous_status),
+        mandate.mandate_status,
+        &mandate.connector,
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(mandate_types::MandateResponse {
+        mandate_id: mandate.mandate_id,
+        status: mandate.mandate_status,
+        connector: mandate.connector,
+        created: mandate.created,
+    }))
+}
+
+/// Revokes every mandate matching `request`'s filters (status / connector / created_time range)
+/// plus any ids listed explicitly in `request.mandate_ids`, returning a per-mandate outcome so a
+/// failure revoking one mandate doesn't hide the rest of the batch's results.
+pub async fn revoke_mandates_bulk(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: mandate_types::MandateRevokeConstraints,
+) -> RouterResponse<mandate_types::MandateRevokeBulkResponse> {
+    let db = state.store.as_ref();
+
+    let matched_mandate_ids = db
+        .find_mandate_ids_by_constraints(
+            merchant_account.get_id(),
+            request.mandate_status,
+            request.connector.as_deref(),
+            request.created_time,
+            &request.mandate_ids,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to resolve mandates matching the bulk revoke constraints")?;
+
+    let mut results = Vec::with_capacity(matched_mandate_ids.len());
+
+    for mandate_id in matched_mandate_ids {
+        // Best-effort: if the lookup fails we still go ahead with the revoke below and just
+        // report `previous_status: None` in the hook payload for this one mandate.
+        let previous_status = db
+            .find_mandate_by_merchant_id_mandate_id(merchant_account.get_id(), &mandate_id)
+            .await
+            .ok()
+            .map(|mandate| mandate.mandate_status);
+
+        let outcome = db
+            .revoke_mandate(
+                merchant_account.get_id(),
+                &key_store,
+                &mandate_id,
+            )
+            .await;
+
+        results.push(match outcome {
+            Ok(mandate) => {
+                notify(
+                    &state,
+                    merchant_account.get_id(),
+                    hooks::MandateHookEvent::Revoked,
+                    &mandate_id,
+                    previous_status,
+                    mandate.mandate_status,
+                    &mandate.connector,
+                )
+                .await;
+
+                mandate_types::MandateRevokeResult {
+                    mandate_id,
+                    status: Some(mandate.mandate_status),
+                    error: None,
+                }
+            }
+            Err(error) => mandate_types::MandateRevokeResult {
+                mandate_id,
+                status: None,
+                error: Some(error.to_string()),
+            },
+        });
+    }
+
+    Ok(ApplicationResponse::Json(
+        mandate_types::MandateRevokeBulkResponse { results },
+    ))
+}
+
+/// Lists mandates for `merchant_account` ordered by `(created_time DESC, id DESC)`, keyset-paged
+/// via `starting_after`/`ending_before` (falling back to `offset` if neither is set).
+pub async fn retrieve_mandates_list(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    _key_store: domain::MerchantKeyStore,
+    request: mandate_types::MandateListConstraints,
+) -> RouterResponse<mandate_types::MandateListResponse> {
+    let db = state.store.as_ref();
+    let limit = request.limit.unwrap_or(10);
+
+    let after = request
+        .starting_after
+        .as_deref()
+        .map(ListCursor::decode)
+        .transpose()?;
+    let before = request
+        .ending_before
+        .as_deref()
+        .map(ListCursor::decode)
+        .transpose()?;
+    let compiled_filter = request
+        .filter
+        .as_deref()
+        .map(filter::parse)
+        .transpose()?
+        .map(|expr| filter::lower_to_sql(&expr));
+    // `filter` takes precedence over the flat fields, so don't also apply them once it's set.
+    let (mandate_status, connector, created_time) = if compiled_filter.is_some() {
+        (None, None, None)
+    } else {
+        (request.mandate_status, request.connector, request.created_time)
+    };
+
+    let mandates = db
+        .find_mandates_by_merchant_id_keyset(
+            merchant_account.get_id(),
+            mandate_status,
+            connector.as_deref(),
+            created_time,
+            compiled_filter.as_ref(),
+            after.as_ref(),
+            before.as_ref(),
+            request.offset.filter(|_| after.is_none() && before.is_none()),
+            // fetch one extra row so we know whether there's another page without a second query
+            limit + 1,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to list mandates")?;
+
+    let has_more = mandates.len() as i64 > limit;
+    let mandates: Vec<_> = mandates.into_iter().take(limit as usize).collect();
+
+    let next_cursor = mandates.last().filter(|_| has_more).map(|mandate| {
+        ListCursor {
+            created_time: mandate.created,
+            mandate_id: mandate.mandate_id.clone(),
+        }
+        .encode()
+    });
+    let prev_cursor = mandates.first().map(|mandate| {
+        ListCursor {
+            created_time: mandate.created,
+            mandate_id: mandate.mandate_id.clone(),
+        }
+        .encode()
+    });
+
+    Ok(ApplicationResponse::Json(
+        mandate_types::MandateListResponse {
+            data: mandates,
+            next_cursor,
+            prev_cursor,
+            has_more,
+        },
+    ))
+}
+
+/// Groups mandates for `merchant_account` by `(mandate_status, connector)`, applying the same
+/// filters as [`retrieve_mandates_list`].
+pub async fn retrieve_mandates_analytics(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    _key_store: domain::MerchantKeyStore,
+    request: mandate_types::MandateListConstraints,
+) -> RouterResponse<mandate_types::MandateAnalyticsResponse> {
+    let db = state.store.as_ref();
+
+    let compiled_filter = request
+        .filter
+        .as_deref()
+        .map(filter::parse)
+        .transpose()?
+        .map(|expr| filter::lower_to_sql(&expr));
+    // `filter` takes precedence over the flat fields, so don't also apply them once it's set.
+    let (mandate_status, connector, created_time) = if compiled_filter.is_some() {
+        (None, None, None)
+    } else {
+        (request.mandate_status, request.connector, request.created_time)
+    };
+
+    let buckets = db
+        .count_mandates_by_status_and_connector(
+            merchant_account.get_id(),
+            mandate_status,
+            connector.as_deref(),
+            created_time,
+            compiled_filter.as_ref(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to aggregate mandate analytics")?;
+
+    Ok(ApplicationResponse::Json(
+        mandate_types::MandateAnalyticsResponse { buckets },
+    ))
+}
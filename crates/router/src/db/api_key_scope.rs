@@ -0,0 +1,34 @@
+use std::collections::HashSet;
+
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::errors::{self, CustomResult},
+    services::authentication::permissions::{Action, ApiKeyScope},
+};
+
+/// Storage-layer access to the scope (`actions`, `expires_at`) attached to an API key, backing
+/// [`crate::services::authentication::permissions::ScopedApiKeyAuth`].
+///
+/// Backed by an `api_key_scopes` table keyed on the key's hash (`actions` as a JSON array,
+/// `expires_at` nullable); the migration for that table lives in `crates/diesel_models`, outside
+/// this series. Needs adding as a supertrait of `StorageInterface` to be reachable via
+/// `state.store`.
+#[async_trait::async_trait]
+pub trait ApiKeyScopeInterface {
+    /// Looks up the scope row for `key_hash`. `Ok(None)` means the key predates scoped
+    /// permissions and should fall back to unscoped `ApiKeyAuth` behaviour.
+    async fn find_api_key_scope_by_hash(
+        &self,
+        key_hash: &str,
+    ) -> CustomResult<Option<ApiKeyScope>, errors::StorageError>;
+
+    /// Creates or replaces the scope row for `key_hash`, e.g. when a merchant mints a
+    /// read-only key from the dashboard.
+    async fn upsert_api_key_scope(
+        &self,
+        key_hash: &str,
+        actions: HashSet<Action>,
+        expires_at: Option<PrimitiveDateTime>,
+    ) -> CustomResult<ApiKeyScope, errors::StorageError>;
+}
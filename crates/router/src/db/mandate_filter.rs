@@ -0,0 +1,50 @@
+use api_models::mandates::{MandateAnalyticsBucket, MandateResponse, MandateStatus};
+use common_utils::{id_type, types::TimeRange};
+
+use crate::core::{
+    errors::{self, CustomResult},
+    mandate::{cursor::ListCursor, filter::CompiledFilter},
+};
+
+/// Storage-layer queries backing mandate listing, filtering, and analytics. Needs adding as a
+/// supertrait of `StorageInterface` to be reachable via `state.store`.
+#[async_trait::async_trait]
+pub trait MandateFilterInterface {
+    /// Resolves the ids a bulk-revoke request touches: the union of `explicit_ids` and whatever
+    /// matches `mandate_status`/`connector`/`created_time`.
+    async fn find_mandate_ids_by_constraints(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        mandate_status: Option<MandateStatus>,
+        connector: Option<&str>,
+        created_time: Option<TimeRange>,
+        explicit_ids: &[String],
+    ) -> CustomResult<Vec<String>, errors::StorageError>;
+
+    /// One page of mandates ordered `(created_time DESC, id DESC)`, keyset-paged via `after`/
+    /// `before`, falling back to `offset` when neither cursor is given.
+    #[allow(clippy::too_many_arguments)]
+    async fn find_mandates_by_merchant_id_keyset(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        mandate_status: Option<MandateStatus>,
+        connector: Option<&str>,
+        created_time: Option<TimeRange>,
+        filter: Option<&CompiledFilter>,
+        after: Option<&ListCursor>,
+        before: Option<&ListCursor>,
+        offset: Option<i64>,
+        limit: i64,
+    ) -> CustomResult<Vec<MandateResponse>, errors::StorageError>;
+
+    /// Counts mandates grouped by `(mandate_status, connector)`, applying the same filters as
+    /// [`find_mandates_by_merchant_id_keyset`].
+    async fn count_mandates_by_status_and_connector(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        mandate_status: Option<MandateStatus>,
+        connector: Option<&str>,
+        created_time: Option<TimeRange>,
+        filter: Option<&CompiledFilter>,
+    ) -> CustomResult<Vec<MandateAnalyticsBucket>, errors::StorageError>;
+}
@@ -0,0 +1,38 @@
+use api_models::mandates::MandateStatus;
+use common_utils::id_type;
+
+use crate::core::{
+    errors::{self, CustomResult},
+    mandate::hooks::{MandateHook, MandateHookEvent},
+};
+
+/// Storage-layer access to registered mandate lifecycle hooks, backing `core::mandate::hooks`.
+///
+/// Backed by a `mandate_hooks` table (`merchant_id`, `event`, `action`) and a
+/// `mandate_notification_state` table tracking the last status a merchant was notified of per
+/// `(merchant_id, mandate_id)`; migrations for both live in `crates/diesel_models`, outside this
+/// series. Needs adding as a supertrait of `StorageInterface` to be reachable via `state.store`.
+#[async_trait::async_trait]
+pub trait MandateHooksInterface {
+    /// All hooks a merchant has registered for `event`.
+    async fn find_mandate_hooks(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        event: MandateHookEvent,
+    ) -> CustomResult<Vec<MandateHook>, errors::StorageError>;
+
+    /// Registers `hook`, replacing any existing hook for the same `(merchant_id, event)`.
+    async fn upsert_mandate_hook(
+        &self,
+        hook: MandateHook,
+    ) -> CustomResult<MandateHook, errors::StorageError>;
+
+    /// Records `new_status` as the last status notified for `mandate_id`, returning whatever
+    /// was recorded before this call (`None` the first time a mandate is notified about).
+    async fn update_mandate_last_notified_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        mandate_id: &str,
+        new_status: MandateStatus,
+    ) -> CustomResult<Option<MandateStatus>, errors::StorageError>;
+}
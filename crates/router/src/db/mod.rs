@@ -0,0 +1,3 @@
+pub mod api_key_scope;
+pub mod mandate_filter;
+pub mod mandate_hooks;
@@ -0,0 +1,50 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::api_locking,
+    services::{api, authentication as auth},
+};
+
+/// API Keys - Update Scope
+///
+/// Grants a key the given `actions`/`expires_at`, e.g. to mint a mandates-read-only key.
+#[utoipa::path(
+    post,
+    path = "/api_keys/{key_hash}/scope",
+    params(
+        ("key_hash" = String, Path, description = "The hash of the api key to scope")
+    ),
+    request_body = ApiKeyScopeRequest,
+    responses(
+        (status = 200, description = "The key's scope was updated", body = ApiKeyScopeRequest),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Api Keys",
+    operation_id = "Update Api Key Scope",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ApiKeyScopeUpdate))]
+pub async fn update_api_key_scope(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<auth::permissions::ApiKeyScopeRequest>,
+) -> HttpResponse {
+    let flow = Flow::ApiKeyScopeUpdate;
+    let key_hash = path.into_inner();
+    let payload = json_payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        move |state, _: (), req, _| {
+            auth::permissions::update_api_key_scope(state, key_hash.clone(), req)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
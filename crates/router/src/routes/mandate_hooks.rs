@@ -0,0 +1,45 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, mandate},
+    services::{api, authentication as auth},
+};
+
+/// Mandates - Register Hook
+///
+/// Registers (or replaces) the hook fired for one mandate lifecycle event.
+#[utoipa::path(
+    post,
+    path = "/mandates/hooks",
+    request_body = MandateHookRegistration,
+    responses(
+        (status = 200, description = "The hook was registered", body = MandateHookRegistration),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Mandates",
+    operation_id = "Register Mandate Hook",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MandateHooksUpdate))]
+pub async fn upsert_mandate_hook(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<mandate::hooks::MandateHookRegistration>,
+) -> HttpResponse {
+    let flow = Flow::MandateHooksUpdate;
+    let payload = json_payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            mandate::hooks::register(state, auth.merchant_account, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
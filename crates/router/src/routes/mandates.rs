@@ -36,6 +36,7 @@ pub async fn get_mandate(
     let mandate_id = mandates::MandateId {
         mandate_id: path.into_inner(),
     };
+    let lock_key = mandate_id.mandate_id.clone();
     Box::pin(api::server_wrap(
         flow,
         state,
@@ -44,8 +45,68 @@ pub async fn get_mandate(
         |state, auth: auth::AuthenticationData, req, _| {
             mandate::get_mandate(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::HeaderAuth(auth::ApiKeyAuth),
-        api_locking::LockAction::NotApplicable,
+        &auth::HeaderAuth(auth::permissions::ScopedApiKeyAuth(flow)),
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: lock_key,
+                api_identifier: api_locking::LockableApis::MandatesRevoke,
+                override_lock_retries: None,
+            },
+        },
+    ))
+    .await
+}
+
+/// Mandates - Revoke Mandates (Bulk)
+///
+/// Revokes every mandate matching the given filters, or the explicit `mandate_ids`, in one
+/// call instead of looping `revoke_mandate` per id.
+#[utoipa::path(
+    post,
+    path = "/mandates/revoke",
+    request_body = MandateRevokeConstraints,
+    responses(
+        (status = 200, description = "The matching mandates were revoked", body = MandateRevokeBulkResponse),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Mandates",
+    operation_id = "Revoke Mandates",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::MandatesRevokeBulk))]
+pub async fn revoke_mandates_bulk(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<mandates::MandateRevokeConstraints>,
+) -> HttpResponse {
+    let flow = Flow::MandatesRevokeBulk;
+    let payload = json_payload.into_inner();
+    // Filter-only selection (the "offboard a connector" case) has no explicit ids to lock on
+    // until the constraints are resolved against the DB, which happens after this lock is
+    // already held; fall back to the same "*" wildcard the retrieve endpoints use below so a
+    // concurrent get/revoke on a filter-matched mandate still contends instead of racing.
+    let lock_key = if payload.mandate_ids.is_empty() {
+        "*".to_string()
+    } else {
+        payload.mandate_ids.join(",")
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            mandate::revoke_mandates_bulk(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::HeaderAuth(auth::permissions::ScopedApiKeyAuth(flow)),
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: lock_key,
+                api_identifier: api_locking::LockableApis::MandatesRevoke,
+                override_lock_retries: None,
+            },
+        },
     ))
     .await
 }
@@ -61,6 +122,7 @@ pub async fn revoke_mandate(
     let mandate_id = mandates::MandateId {
         mandate_id: path.into_inner(),
     };
+    let lock_key = mandate_id.mandate_id.clone();
     Box::pin(api::server_wrap(
         flow,
         state,
@@ -69,8 +131,14 @@ pub async fn revoke_mandate(
         |state, auth: auth::AuthenticationData, req, _| {
             mandate::revoke_mandate(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::HeaderAuth(auth::ApiKeyAuth),
-        api_locking::LockAction::NotApplicable,
+        &auth::HeaderAuth(auth::permissions::ScopedApiKeyAuth(flow)),
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: lock_key,
+                api_identifier: api_locking::LockableApis::MandatesRevoke,
+                override_lock_retries: None,
+            },
+        },
     ))
     .await
 }
@@ -87,10 +155,13 @@ pub async fn revoke_mandate(
         ("created_time.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the mandate created time"),
         ("created_time.lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the mandate created time"),
         ("created_time.gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the mandate created time"),
-        ("offset" = Option<i64>, Query, description = "The number of Mandate Objects to skip when retrieving the list Mandates."),
+        ("offset" = Option<i64>, Query, description = "The number of Mandate Objects to skip when retrieving the list Mandates. Ignored when a cursor is supplied."),
+        ("starting_after" = Option<String>, Query, description = "Opaque cursor; fetch the page after the one it was returned in"),
+        ("ending_before" = Option<String>, Query, description = "Opaque cursor; fetch the page before the one it was returned in"),
+        ("filter" = Option<String>, Query, description = "A filter expression over connector/mandate_status/created_time/mandate_id, e.g. `connector = \"stripe\" AND mandate_status != \"revoked\"`. Takes precedence over the flat filter fields when present."),
     ),
     responses(
-        (status = 200, description = "The mandate list was retrieved successfully", body = Vec<MandateResponse>),
+        (status = 200, description = "The mandate list was retrieved successfully", body = MandateListResponse),
         (status = 401, description = "Unauthorized request")
     ),
     tag = "Mandates",
@@ -114,13 +185,76 @@ pub async fn retrieve_mandates_list(
             mandate::retrieve_mandates_list(state, auth.merchant_account, auth.key_store, req)
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::HeaderAuth(auth::permissions::ScopedApiKeyAuth(flow)),
             &auth::JWTAuth {
                 permission: Permission::MerchantMandateRead,
             },
             req.headers(),
         ),
-        api_locking::LockAction::NotApplicable,
+        // No single mandate id is known before the query runs, so hold the literal "*" key: this
+        // contends with other list/analytics calls and filter-only bulk revokes, not with a
+        // single-mandate get/revoke, which locks on its own id.
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: "*".to_string(),
+                api_identifier: api_locking::LockableApis::MandatesRevoke,
+                override_lock_retries: None,
+            },
+        },
+    ))
+    .await
+}
+
+/// Mandates - Analytics
+///
+/// Groups mandates matching the given filters by `(mandate_status, connector)`, so merchants can
+/// get aggregate counts in one call instead of issuing a filtered list request per combination.
+#[utoipa::path(
+    get,
+    path = "/mandates/analytics",
+    params(
+        ("mandate_status" = Option<MandateStatus>, Query, description = "The status of mandate"),
+        ("connector" = Option<String>, Query, description = "The connector linked to mandate"),
+        ("filter" = Option<String>, Query, description = "A filter expression over connector/mandate_status/created_time/mandate_id"),
+    ),
+    responses(
+        (status = 200, description = "Mandate counts grouped by status and connector", body = MandateAnalyticsResponse),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Mandates",
+    operation_id = "Mandate Analytics",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MandatesAnalytics))]
+pub async fn retrieve_mandates_analytics(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<api_models::mandates::MandateListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::MandatesAnalytics;
+    let payload = payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            mandate::retrieve_mandates_analytics(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::permissions::ScopedApiKeyAuth(flow)),
+            &auth::JWTAuth {
+                permission: Permission::MerchantMandateRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: "*".to_string(),
+                api_identifier: api_locking::LockableApis::MandatesRevoke,
+                override_lock_retries: None,
+            },
+        },
     ))
     .await
 }
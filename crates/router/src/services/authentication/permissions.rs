@@ -0,0 +1,218 @@
+use std::collections::HashSet;
+
+use common_utils::date_time;
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use router_env::Flow;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use super::{get_api_key, ApiKeyAuth, AuthenticateAndFetch, AuthenticationData, AuthenticationType};
+use crate::{
+    core::{
+        api_keys,
+        errors::{self, ApiErrorResponse, RouterResponse, RouterResult},
+    },
+    db::api_key_scope::ApiKeyScopeInterface,
+    routes::app::AppState,
+    services::ApplicationResponse,
+};
+
+/// A single permission a scoped API key can be granted. `Wildcard` matches any action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Action {
+    #[serde(rename = "mandates.read")]
+    MandatesRead,
+    #[serde(rename = "mandates.revoke")]
+    MandatesRevoke,
+    #[serde(rename = "mandates.list")]
+    MandatesList,
+    #[serde(rename = "*")]
+    Wildcard,
+}
+
+impl Action {
+    /// The action a request on this flow must be granted. `None` for flows not yet covered by
+    /// scoped keys, meaning a scoped key can never call them.
+    pub fn required_for_flow(flow: Flow) -> Option<Self> {
+        match flow {
+            Flow::MandatesRetrieve => Some(Self::MandatesRead),
+            Flow::MandatesRevoke | Flow::MandatesRevokeBulk => Some(Self::MandatesRevoke),
+            Flow::MandatesList | Flow::MandatesAnalytics => Some(Self::MandatesList),
+            _ => None,
+        }
+    }
+
+    fn matches(self, granted: &HashSet<Self>) -> bool {
+        granted.contains(&Self::Wildcard) || granted.contains(&self)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::MandatesRead => "mandates.read",
+            Self::MandatesRevoke => "mandates.revoke",
+            Self::MandatesList => "mandates.list",
+            Self::Wildcard => "*",
+        }
+    }
+}
+
+/// The subset of an API key's row that scoped-permission checks care about. `expires_at: None`
+/// means the key never expires.
+#[derive(Debug, Clone)]
+pub struct ApiKeyScope {
+    pub actions: HashSet<Action>,
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
+impl ApiKeyScope {
+    fn is_expired(&self, now: PrimitiveDateTime) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+/// Request body for (re)granting a key's scope, e.g. a merchant minting a read-only key.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ApiKeyScopeRequest {
+    pub actions: HashSet<Action>,
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
+/// Admin entry point for setting a key's scope. `key_hash` is the same hash
+/// [`get_api_key`]-derived keys are looked up by in [`ScopedApiKeyAuth`].
+pub async fn update_api_key_scope(
+    state: AppState,
+    key_hash: String,
+    req: ApiKeyScopeRequest,
+) -> RouterResponse<ApiKeyScopeRequest> {
+    let scope = state
+        .store
+        .upsert_api_key_scope(&key_hash, req.actions, req.expires_at)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to persist api key scope")?;
+
+    Ok(ApplicationResponse::Json(ApiKeyScopeRequest {
+        actions: scope.actions,
+        expires_at: scope.expires_at,
+    }))
+}
+
+/// `HeaderAuth`-compatible authenticator that wraps [`ApiKeyAuth`] and additionally enforces
+/// [`ApiKeyScope`], rejecting expired keys or ones missing the action required by `self.0`.
+#[derive(Debug, Clone, Copy)]
+pub struct ScopedApiKeyAuth(pub Flow);
+
+#[async_trait::async_trait]
+impl AuthenticateAndFetch<AuthenticationData, AppState> for ScopedApiKeyAuth {
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &actix_web::http::header::HeaderMap,
+        state: &AppState,
+    ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
+        let required_action = Action::required_for_flow(self.0).ok_or(ApiErrorResponse::Forbidden {
+            resource: "this endpoint does not support scoped API keys".to_string(),
+        })?;
+
+        let (auth_data, auth_type) = ApiKeyAuth
+            .authenticate_and_fetch(request_headers, state)
+            .await?;
+
+        let api_key = get_api_key(request_headers).change_context(ApiErrorResponse::Unauthorized)?;
+        let hash_key = state.conf.api_keys.get_inner().get_hash_key()?;
+        let hashed_api_key = api_keys::PlaintextApiKey::from(api_key).keyed_hash(hash_key.peek());
+        let scope = state
+            .store
+            .find_api_key_scope_by_hash(&hashed_api_key.into_inner())
+            .await
+            .change_context(ApiErrorResponse::Unauthorized)
+            .attach_printable("failed to resolve scoped permissions for api key")?;
+
+        // Keys minted before scoped permissions existed have no scope row at all; fall back to
+        // today's plain-`ApiKeyAuth` behaviour for them instead of locking them out.
+        let Some(scope) = scope else {
+            return Ok((auth_data, auth_type));
+        };
+
+        if scope.is_expired(date_time::now()) {
+            return Err(ApiErrorResponse::Forbidden {
+                resource: "api_key".to_string(),
+            }
+            .into());
+        }
+
+        if !required_action.matches(&scope.actions) {
+            return Err(ApiErrorResponse::Forbidden {
+                resource: required_action.as_str().to_string(),
+            }
+            .into());
+        }
+
+        Ok((auth_data, auth_type))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn required_for_flow_maps_every_mandate_flow() {
+        assert_eq!(
+            Action::required_for_flow(Flow::MandatesRetrieve),
+            Some(Action::MandatesRead)
+        );
+        assert_eq!(
+            Action::required_for_flow(Flow::MandatesRevoke),
+            Some(Action::MandatesRevoke)
+        );
+        assert_eq!(
+            Action::required_for_flow(Flow::MandatesRevokeBulk),
+            Some(Action::MandatesRevoke)
+        );
+        assert_eq!(
+            Action::required_for_flow(Flow::MandatesList),
+            Some(Action::MandatesList)
+        );
+        assert_eq!(
+            Action::required_for_flow(Flow::MandatesAnalytics),
+            Some(Action::MandatesList)
+        );
+        assert_eq!(Action::required_for_flow(Flow::PaymentsCreate), None);
+    }
+
+    #[test]
+    fn wildcard_matches_any_action() {
+        let granted = HashSet::from([Action::Wildcard]);
+        assert!(Action::MandatesRead.matches(&granted));
+        assert!(Action::MandatesRevoke.matches(&granted));
+    }
+
+    #[test]
+    fn exact_action_matches_only_itself() {
+        let granted = HashSet::from([Action::MandatesRead]);
+        assert!(Action::MandatesRead.matches(&granted));
+        assert!(!Action::MandatesRevoke.matches(&granted));
+    }
+
+    #[test]
+    fn no_expiry_never_expires() {
+        let scope = ApiKeyScope {
+            actions: HashSet::new(),
+            expires_at: None,
+        };
+        assert!(!scope.is_expired(datetime!(2999-01-01 00:00:00)));
+    }
+
+    #[test]
+    fn expired_when_now_is_past_expiry() {
+        let scope = ApiKeyScope {
+            actions: HashSet::new(),
+            expires_at: Some(datetime!(2020-01-01 00:00:00)),
+        };
+        assert!(scope.is_expired(datetime!(2020-01-01 00:00:01)));
+        assert!(!scope.is_expired(datetime!(2019-12-31 23:59:59)));
+    }
+}
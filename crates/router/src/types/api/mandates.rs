@@ -0,0 +1,11 @@
+pub use api_models::mandates::{
+    MandateAnalyticsResponse, MandateListConstraints, MandateListResponse,
+    MandateRevokeBulkResponse, MandateRevokeConstraints, MandateRevokeResult, MandateResponse,
+    MandateStatus,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MandateId {
+    pub mandate_id: String,
+}